@@ -0,0 +1,66 @@
+//! [`Arbitrary`] implementations for the fixed-width, sub-byte unsigned
+//! integer types (`u1`..`u63`) from the [`arbitrary-int`](https://docs.rs/arbitrary-int)
+//! crate, gated behind the `arbitrary-int` feature.
+//!
+//! These are the types the `arbitrary-int`/`bilge` ecosystem uses to model
+//! bitfields and hardware registers (QEMU's Rust device code among them).
+//! Each `uN` only ever consumes `ceil(N / 8)` bytes, so its `size_hint` is
+//! exact rather than falling back to the backing integer's full width.
+
+use crate::{Arbitrary, Result, Unstructured};
+use arbitrary_int::*;
+
+macro_rules! impl_arbitrary_for_fixed_width_uint {
+    ( $( $ty:ident: $bits:literal; )* ) => {
+        $(
+            impl<'a> Arbitrary<'a> for $ty {
+                fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                    const BYTES: usize = ($bits as usize).div_ceil(8);
+                    let mut buf = [0u8; 8];
+                    u.fill_buffer(&mut buf[..BYTES])?;
+                    let raw = u64::from_le_bytes(buf) & $ty::MASK as u64;
+                    Ok($ty::new(raw as _))
+                }
+
+                #[inline]
+                fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                    const BYTES: usize = ($bits as usize).div_ceil(8);
+                    (BYTES, Some(BYTES))
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_for_fixed_width_uint! {
+    u1: 1; u2: 2; u3: 3; u4: 4; u5: 5; u6: 6; u7: 7; u9: 9;
+    u10: 10; u11: 11; u12: 12; u13: 13; u14: 14; u15: 15; u17: 17; u18: 18;
+    u19: 19; u20: 20; u21: 21; u22: 22; u23: 23; u24: 24; u25: 25; u26: 26;
+    u27: 27; u28: 28; u29: 29; u30: 30; u31: 31; u33: 33; u34: 34; u35: 35;
+    u36: 36; u37: 37; u38: 38; u39: 39; u40: 40; u41: 41; u42: 42; u43: 43;
+    u44: 44; u45: 45; u46: 46; u47: 47; u48: 48; u49: 49; u50: 50; u51: 51;
+    u52: 52; u53: 53; u54: 54; u55: 55; u56: 56; u57: 57; u58: 58; u59: 59;
+    u60: 60; u61: 61; u62: 62; u63: 63;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_hint_is_exact_byte_count() {
+        assert_eq!((1, Some(1)), u1::size_hint(0));
+        assert_eq!((1, Some(1)), u7::size_hint(0));
+        assert_eq!((2, Some(2)), u9::size_hint(0));
+        assert_eq!((4, Some(4)), u31::size_hint(0));
+        assert_eq!((8, Some(8)), u63::size_hint(0));
+    }
+
+    #[test]
+    fn arbitrary_masks_into_range() {
+        let data = [0xff; 8];
+        let mut u = Unstructured::new(&data);
+        let value = u7::arbitrary(&mut u).unwrap();
+        assert!(value.value() <= u7::MAX.value());
+    }
+}
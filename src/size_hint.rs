@@ -23,6 +23,85 @@ pub fn recursion_guard(
 
 type SizeHintFunc = fn(usize) -> (usize, Option<usize>);
 
+/// Tracks the recursion state for [`fixpoint_size_hint`].
+///
+/// While a fixpoint is being computed, `Ctx` remembers which [`TypeId`]s are
+/// currently being evaluated, so that a type which refers to itself (directly
+/// or through some other type) resolves to the current iteration's
+/// accumulator instead of recursing infinitely.
+#[derive(Default)]
+pub struct Ctx {
+    stack: Vec<std::any::TypeId>,
+    accum: std::collections::HashMap<std::any::TypeId, (usize, Option<usize>)>,
+}
+
+impl Ctx {
+    /// Compute the size hint of `T` via `f`, unless `T` is already being
+    /// computed higher up the stack, in which case the in-progress
+    /// accumulator for `T` is returned instead of recursing. Only the type
+    /// passed to [`fixpoint_size_hint`] has an accumulator; any other type
+    /// found on the stack resolves to the bottom element `(0, Some(0))`.
+    pub fn hint<T: 'static>(
+        &mut self,
+        f: impl FnOnce(&mut Ctx) -> (usize, Option<usize>),
+    ) -> (usize, Option<usize>) {
+        let id = std::any::TypeId::of::<T>();
+        if self.stack.contains(&id) {
+            return self.accum.get(&id).copied().unwrap_or((0, Some(0)));
+        }
+        self.stack.push(id);
+        let hint = f(self);
+        self.stack.pop();
+        hint
+    }
+}
+
+/// Compute the least fixed point of a recursive `size_hint` function.
+///
+/// `recursion_guard` bails out at a fixed depth and reports the useless
+/// `(usize::MAX, None)` for any recursive type, even though such a type
+/// always has a finite minimum encoding (its base case). This computes that
+/// minimum instead: `f` is treated as a monotone function over the
+/// `(lower, upper)` lattice (ordered the same way [`and`] and [`or`] combine
+/// it), starting every self-reference at the bottom element `(0, Some(0))`
+/// and re-evaluating `f` with the previous iteration's result substituted in
+/// for `T` via [`Ctx`], until the lower bound stops increasing. Because `f`
+/// is built out of `and`/`or`, the lower bound is monotonically
+/// non-decreasing, and it converges once a base case reachable via `or`
+/// bounds it; the upper bound
+/// collapses to `None` for good the moment a self-reference appears under an
+/// unbounded combinator.
+///
+/// Like [`recursion_guard`], this caps the number of iterations it will run:
+/// a malformed `f` whose lower bound never stabilizes (e.g. a recursive type
+/// with no base case reachable via `or`) would otherwise spin forever, so
+/// once `MAX_ITERS` rounds pass without convergence this gives up and
+/// returns `(usize::MAX, None)`, the same fallback `recursion_guard` uses.
+pub fn fixpoint_size_hint<T: 'static>(
+    f: impl Fn(&mut Ctx) -> (usize, Option<usize>),
+) -> (usize, Option<usize>) {
+    const MAX_ITERS: usize = 100;
+    let id = std::any::TypeId::of::<T>();
+    let mut ctx = Ctx::default();
+    let mut prev = (0, Some(0));
+    for _ in 0..MAX_ITERS {
+        ctx.accum.insert(id, prev);
+        ctx.stack.clear();
+        ctx.stack.push(id);
+        let next = f(&mut ctx);
+        if next.0 == prev.0 {
+            // The lower bound has converged. If the upper bound has too,
+            // we're at a true fixed point. Otherwise the self-reference
+            // keeps growing the upper bound without ever tightening the
+            // lower bound any further, which means `T` can recur without
+            // limit: collapse the upper bound to `None`.
+            return if next.1 == prev.1 { next } else { (next.0, None) };
+        }
+        prev = next;
+    }
+    (usize::MAX, None)
+}
+
 /// Take the sum of the `lhs` and `rhs` size hints.
 #[inline]
 pub fn and(lhs: (usize, Option<usize>), rhs: (usize, Option<usize>)) -> (usize, Option<usize>) {
@@ -42,6 +121,47 @@ pub fn and_all(hints: &[(usize, Option<usize>)]) -> (usize, Option<usize>) {
     hints.iter().copied().fold((0, Some(0)), and)
 }
 
+/// Take the size hint for `n` back-to-back repetitions of `hint`.
+///
+/// This is equivalent to, but cheaper than, folding `hint` through [`and`]
+/// `n` times (as `[T; N]`, fixed-length collections, and
+/// `arbitrary_take_rest` would otherwise have to): it's the O(1) saturating
+/// multiply `(hint.0 * n, hint.1 * n)` instead of an O(n) summation.
+///
+/// `n == 0` always reports `(0, Some(0))`, the size of consuming nothing,
+/// regardless of `hint`.
+#[inline]
+pub fn mul(hint: (usize, Option<usize>), n: usize) -> (usize, Option<usize>) {
+    if n == 0 {
+        return (0, Some(0));
+    }
+    (
+        hint.0.saturating_mul(n),
+        hint.1.map(|upper| upper.saturating_mul(n)),
+    )
+}
+
+/// Take the size hint for `n` back-to-back repetitions of a lazily evaluated
+/// `hint`.
+///
+/// `hint` is invoked only once and the result is [`mul`] of that single
+/// hint. If the element hint is already the maximum size
+/// `(usize::MAX, None)`, it is returned as is without multiplying; a product
+/// that merely saturates keeps whatever upper bound [`mul`] gives it.
+///
+/// `n == 0` always reports `(0, Some(0))` without invoking `hint` at all.
+#[inline]
+pub fn mul_lazy(hint: SizeHintFunc, n: usize, depth: usize) -> (usize, Option<usize>) {
+    if n == 0 {
+        return (0, Some(0));
+    }
+    let single = hint(depth);
+    if matches!(single, (usize::MAX, None)) {
+        return single;
+    }
+    mul(single, n)
+}
+
 /// Take the sum of all of the given size hints.
 ///
 /// Unlike, [`and_all`][], the hints are passed as function that are evaluated lazily.
@@ -108,8 +228,115 @@ pub fn or_all_lazy(hints: &[SizeHintFunc], depth: usize) -> (usize, Option<usize
     }
 }
 
+/// Runtime verification that an [`Arbitrary`][crate::Arbitrary]
+/// implementation's `size_hint` actually brackets the number of bytes it
+/// consumes, modeled on the `Exact`/`Inexact` hint checking that itertools
+/// uses in its own quickcheck suite.
+///
+/// This is a debugging aid for crate authors who want to fuzz-test their own
+/// `Arbitrary`/`size_hint` impls for consistency; it is not used by this
+/// crate's own implementations and is gated behind the `size_hint_checks`
+/// feature to keep it out of the default build.
+#[cfg(feature = "size_hint_checks")]
+pub mod checked {
+    use crate::{Arbitrary, Result, Unstructured};
+
+    /// Call `T::arbitrary`, then assert that the number of bytes it actually
+    /// consumed falls within the bounds reported by `T::size_hint(0)`.
+    ///
+    /// Panics, naming `T` and the reported vs. actual byte counts, if the
+    /// hint's lower bound claims more bytes than were consumed (capped at the
+    /// bytes available) or its finite upper bound was exceeded.
+    pub fn check_hint<'a, T: Arbitrary<'a>>(u: &mut Unstructured<'a>) -> Result<T> {
+        let before = u.len();
+        let value = T::arbitrary(u)?;
+        let consumed = before - u.len();
+        let (lower, upper) = T::size_hint(0);
+        // `Unstructured::fill_buffer` never errors on exhausted data: it
+        // zero-pads and consumes whatever bytes remain, so `consumed` can
+        // legitimately fall short of `lower` once the input runs dry. Only
+        // hold the impl to `lower` up to however many bytes were actually
+        // available to begin with.
+        let expected_lower = std::cmp::min(lower, before);
+        assert!(
+            consumed >= expected_lower,
+            "{}::size_hint reported a lower bound of {} bytes, but only {} of {} available bytes were consumed",
+            std::any::type_name::<T>(),
+            lower,
+            consumed,
+            before,
+        );
+        if let Some(upper) = upper {
+            assert!(
+                consumed <= upper,
+                "{}::size_hint reported an upper bound of {} bytes, but {} were consumed",
+                std::any::type_name::<T>(),
+                upper,
+                consumed,
+            );
+        }
+        Ok(value)
+    }
+
+    /// Deliberately widen `hint` by `k` on each side.
+    ///
+    /// Mirrors itertools' `Inexact::loosen_bounds`: the result is still a
+    /// sound bracket of the real consumption, but consumers can no longer
+    /// assume it's tight, so code built against it can be exercised against
+    /// imprecise-but-valid hints instead of only ever-exact ones.
+    #[inline]
+    pub fn loosen_bounds(hint: (usize, Option<usize>), k: usize) -> (usize, Option<usize>) {
+        (
+            hint.0.saturating_sub(k),
+            hint.1.map(|upper| upper.saturating_add(k)),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn fixpoint_size_hint_recursive_list() {
+        // Models `struct List(Option<(u8, Box<List>)>)`: either the empty
+        // list (0 bytes), or a byte plus another list.
+        struct List;
+        let hint = super::fixpoint_size_hint::<List>(|ctx| {
+            super::or(
+                (0, Some(0)),
+                super::and((1, Some(1)), ctx.hint::<List>(|_| unreachable!())),
+            )
+        });
+        // The tight lower bound is the empty-list base case; the upper bound
+        // is unbounded since the list can grow without limit.
+        assert_eq!((0, None), hint);
+    }
+
+    #[test]
+    fn fixpoint_size_hint_recursive_non_empty_list() {
+        // Models `struct NonEmptyList(u8, Option<Box<NonEmptyList>>)`: always
+        // at least one byte.
+        struct NonEmptyList;
+        let hint = super::fixpoint_size_hint::<NonEmptyList>(|ctx| {
+            super::and(
+                (1, Some(1)),
+                super::or((0, Some(0)), ctx.hint::<NonEmptyList>(|_| unreachable!())),
+            )
+        });
+        assert_eq!((1, None), hint);
+    }
+
+    #[test]
+    fn fixpoint_size_hint_non_terminating_gives_up() {
+        // Models a type with no base case reachable via `or` (e.g. a
+        // mis-derived recursive type): the lower bound never stabilizes, so
+        // this must bail out instead of spinning forever.
+        struct Bad;
+        let hint = super::fixpoint_size_hint::<Bad>(|ctx| {
+            super::and((1, Some(1)), ctx.hint::<Bad>(|_| unreachable!()))
+        });
+        assert_eq!((usize::MAX, None), hint);
+    }
+
     #[test]
     fn and() {
         assert_eq!((5, Some(5)), super::and((2, Some(2)), (3, Some(3))));
@@ -146,6 +373,42 @@ mod tests {
             super::and_all(&[(1, None), (2, Some(2)), (4, Some(4))])
         );
     }
+
+    #[test]
+    fn mul() {
+        assert_eq!((0, Some(0)), super::mul((0, Some(0)), 3));
+        assert_eq!((6, Some(6)), super::mul((2, Some(2)), 3));
+        assert_eq!((6, None), super::mul((2, None), 3));
+        // Zero repetitions always consume zero bytes, regardless of the
+        // element's own hint (even an already-unbounded one).
+        assert_eq!((0, Some(0)), super::mul((2, Some(2)), 0));
+        assert_eq!((0, Some(0)), super::mul((2, None), 0));
+        assert_eq!((0, Some(0)), super::mul((usize::MAX, None), 0));
+        // Overflow saturates to a finite `Some(usize::MAX)`-style value,
+        // like every other combinator in this module, rather than
+        // collapsing to `None`.
+        assert_eq!(
+            (usize::MAX, Some(usize::MAX)),
+            super::mul((usize::MAX, Some(usize::MAX)), 2)
+        );
+    }
+
+    #[test]
+    fn mul_lazy() {
+        assert_eq!((6, Some(6)), super::mul_lazy(|_| (2, Some(2)), 3, 0));
+        assert_eq!((6, None), super::mul_lazy(|_| (2, None), 3, 0));
+        assert_eq!(
+            (usize::MAX, None),
+            super::mul_lazy(|_| (usize::MAX, None), 3, 0)
+        );
+        // Zero repetitions must report zero bytes without even invoking
+        // `hint` (an already-saturated element hint must not leak through).
+        assert_eq!(
+            (0, Some(0)),
+            super::mul_lazy(|_| unreachable!(), 0, 0)
+        );
+    }
+
     #[test]
     fn and_all_lazy() {
         assert_eq!((0, Some(0)), super::and_all_lazy(&[], 0));
@@ -208,4 +471,12 @@ mod tests {
             super::or_all_lazy(&[|_| (1, None), |_| (2, Some(2)), |_| (4, Some(4))], 0)
         );
     }
+
+    #[cfg(feature = "size_hint_checks")]
+    #[test]
+    fn loosen_bounds() {
+        assert_eq!((2, Some(8)), super::checked::loosen_bounds((4, Some(6)), 2));
+        assert_eq!((0, Some(8)), super::checked::loosen_bounds((1, Some(6)), 2));
+        assert_eq!((2, None), super::checked::loosen_bounds((4, None), 2));
+    }
 }